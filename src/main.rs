@@ -25,14 +25,14 @@ fn main() {
     println!("Evaluating VDF...");
 
     let (elapsed, witness) = measure_time(|| {
-        vdf_mod_sqrt::eval(&modulus, &seed, DIFFICULTY)
+        vdf_mod_sqrt::eval(&modulus, &seed, DIFFICULTY).unwrap()
     });
     println!("Response is: 0x{:x}, elapsed: {}", &witness, elapsed);
 
     println!("Verifying VDF...");
 
     let (elapsed, is_verified) = measure_time(|| {
-        vdf_mod_sqrt::verify(&modulus, &seed, DIFFICULTY, &witness)
+        vdf_mod_sqrt::verify(&modulus, &seed, DIFFICULTY, &witness).unwrap()
     });
     println!("Verified: {}, elapsed: {}", is_verified, elapsed);
 